@@ -33,4 +33,6 @@ pub enum ParseNodeError {
     MissingChild(String, String),
     #[error("No attribute with key '{0}' found in <{1}>")]
     MissingAttribute(String, String),
+    #[error("Expected a Record::Element to build a Node from, but found a {0} value")]
+    NotAnElement(String),
 }
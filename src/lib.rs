@@ -16,5 +16,7 @@
 pub mod error;
 /// High level representation of an XML DOM element.
 pub mod node;
+/// A lossless, dynamically-typed value representation of a [`Node`](crate::node::Node).
+pub mod record;
 /// Contains helper traits to work with [Nodes](crate::node::Node).
 pub mod traits;
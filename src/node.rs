@@ -7,29 +7,189 @@ use std::str::FromStr;
 #[cfg(feature = "log")]
 use log::{error, info, trace, warn};
 use quick_xml::events::attributes::Attribute;
-use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::events::{BytesCData, BytesEnd, BytesPI, BytesStart, BytesText, Event};
 use quick_xml::name::QName;
 use quick_xml::{Reader, Writer};
 
 use crate::error::Error;
 use crate::error::ParseNodeError;
 use crate::error::Result;
+use crate::record::Record;
+
+/// Maps a namespace prefix (`None` for the default `xmlns=`) to the URI it is bound to.
+type NamespaceBindings = HashMap<Option<String>, String>;
+
+/// Splits a possibly `prefix:local` qualified name into just its local part.
+fn local_name(name: &str) -> &str {
+    name.split_once(':').map_or(name, |(_, local)| local)
+}
+
+/// Splits a possibly `prefix:local` qualified name into just its prefix, if it has one.
+fn name_prefix(name: &str) -> Option<&str> {
+    name.split_once(':').map(|(prefix, _)| prefix)
+}
+
+/// An order-preserving, [`HashMap`]-like collection of XML attribute key/value pairs, each optionally carrying
+/// its own resolved namespace URI (attributes, unlike elements, never inherit the default `xmlns=` namespace).
+///
+/// Iterating an [`AttributeMap`] always yields pairs in the order they were first inserted, e.g. the order they
+/// appeared in the source document, so a parse-then-write round-trip does not reshuffle attributes.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct AttributeMap(Vec<(String, Option<String>, String)>);
+
+impl AttributeMap {
+    fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _, _)| k == key)
+            .map(|(_, _, v)| v.as_str())
+    }
+
+    /// Finds the value of the attribute whose resolved namespace and local name match the given values.
+    fn get_by_qname(&self, namespace: Option<&str>, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, ns, _)| local_name(k) == name && ns.as_deref() == namespace)
+            .map(|(_, _, v)| v.as_str())
+    }
+
+    fn contains_key(&self, key: &str) -> bool {
+        self.0.iter().any(|(k, _, _)| k == key)
+    }
+
+    fn insert(&mut self, key: String, value: String) {
+        self.insert_namespaced(key, None, value);
+    }
+
+    /// Inserts an attribute together with its resolved namespace, as produced by parsing.
+    fn insert_namespaced(&mut self, key: String, namespace: Option<String>, value: String) {
+        if let Some(entry) = self.0.iter_mut().find(|(k, _, _)| *k == key) {
+            entry.1 = namespace;
+            entry.2 = value;
+        } else {
+            self.0.push((key, namespace, value));
+        }
+    }
+
+    fn extend(&mut self, iter: impl IntoIterator<Item = (String, String)>) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, _, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Iterates the `(key, namespace, value)` triples, preserving each attribute's resolved namespace.
+    fn iter_namespaced(&self) -> impl Iterator<Item = (&str, Option<&str>, &str)> {
+        self.0
+            .iter()
+            .map(|(k, ns, v)| (k.as_str(), ns.as_deref(), v.as_str()))
+    }
+
+    /// Iterates the `(prefix, namespace)` pairs used by this map's prefixed, namespaced attributes, in attribute
+    /// order, for re-declaring `xmlns:prefix` bindings on write. A prefix shared by several attributes is yielded
+    /// once per attribute.
+    fn namespaced_prefixes(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().filter_map(|(key, namespace, _)| {
+            namespace
+                .as_deref()
+                .and_then(|ns| name_prefix(key).map(|prefix| (prefix, ns)))
+        })
+    }
+}
+
+impl FromIterator<(String, String)> for AttributeMap {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+/// A single piece of ordered content inside a [`Node`]: either a run of text or a nested element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Child {
+    /// A run of character data.
+    Text(String),
+    /// A nested element.
+    Element(Node),
+    /// A `<!-- ... -->` comment, with the delimiters stripped.
+    Comment(String),
+    /// A `<![CDATA[ ... ]]>` section, with the delimiters stripped and its content left un-escaped.
+    CData(String),
+    /// A `<? ... ?>` processing instruction, with the delimiters stripped.
+    ProcessingInstruction(String),
+}
+
+impl Child {
+    /// Converts this [`Child`] into a lossless [`Record`] value.
+    fn to_record(&self) -> Record {
+        match self {
+            Self::Text(text) => Record::Text(text.clone()),
+            Self::Element(node) => node.to_record(),
+            Self::Comment(text) => Record::Comment(text.clone()),
+            Self::CData(text) => Record::CData(text.clone()),
+            Self::ProcessingInstruction(text) => Record::ProcessingInstruction(text.clone()),
+        }
+    }
+
+    /// Builds a [`Child`] back from a [`Record`] produced by [`Child::to_record`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `record` is a [`Record::Element`] that cannot be converted into a
+    /// [`Node`].
+    fn try_from_record(record: &Record) -> Result<Self> {
+        Ok(match record {
+            Record::Text(text) => Self::Text(text.clone()),
+            Record::Comment(text) => Self::Comment(text.clone()),
+            Record::CData(text) => Self::CData(text.clone()),
+            Record::ProcessingInstruction(text) => Self::ProcessingInstruction(text.clone()),
+            Record::Element { .. } => Self::Element(Node::try_from_record(record)?),
+        })
+    }
+}
 
 /// A high level tree representation of an XML DOM class.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Node {
     name: String,
-    content: String,
-    attributes: HashMap<String, String>,
-    childs: Vec<Node>,
+    namespace: Option<String>,
+    attributes: AttributeMap,
+    children: Vec<Child>,
+}
+
+/// A depth-first, pre-order [`Iterator`] over the descendants of a [`Node`], returned by [`Node::descendants`].
+#[derive(Debug, Default, Clone)]
+pub struct Descendants<'a> {
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> Iterator for Descendants<'a> {
+    type Item = &'a Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        let mut childs: Vec<&'a Node> = node.childs().collect();
+        childs.reverse();
+        self.stack.extend(childs);
+        Some(node)
+    }
 }
 
 /// A builder for a [`Node`] struct.
 #[derive(Debug, Default, Clone)]
 pub struct NodeBuilder<'a> {
     name: &'a str,
+    namespace: Option<&'a str>,
     content: &'a str,
-    attributes: HashMap<String, String>,
+    attributes: AttributeMap,
     childs: Vec<Node>,
 }
 
@@ -42,16 +202,69 @@ impl Node {
         NodeBuilder::new(name)
     }
 
-    /// Gets the name of the current [`Node`].
+    /// Gets the name of the current [`Node`], including a namespace prefix if one was present in the source.
     #[must_use]
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
 
-    /// Gets the content of the current [`Node`].
+    /// Gets the local name of the current [`Node`], i.e. its name with any namespace prefix stripped.
+    #[must_use]
+    pub fn local_name(&self) -> &str {
+        local_name(&self.name)
+    }
+
+    /// Gets the namespace prefix of this [`Node`]'s name, if it has one.
+    fn name_prefix(&self) -> Option<&str> {
+        self.name.split_once(':').map(|(prefix, _)| prefix)
+    }
+
+    /// Gets the resolved namespace URI of the current [`Node`], if it (or one of its ancestors) declared one.
     #[must_use]
-    pub fn content(&self) -> &str {
-        self.content.as_str()
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Gets the concatenation of all direct text [children](Child::Text) of the current [`Node`].
+    #[must_use]
+    pub fn content(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|child| match child {
+                Child::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Gets the ordered children of the [`Node`], interleaving text and nested elements as they appeared in
+    /// (or were added to) the document.
+    pub fn children(&self) -> impl Iterator<Item = &Child> {
+        self.children.iter()
+    }
+
+    /// Gets the direct comment children of the [`Node`].
+    pub fn comments(&self) -> impl Iterator<Item = &str> {
+        self.children.iter().filter_map(|child| match child {
+            Child::Comment(text) => Some(text.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Gets the direct CDATA-section children of the [`Node`].
+    pub fn cdata(&self) -> impl Iterator<Item = &str> {
+        self.children.iter().filter_map(|child| match child {
+            Child::CData(text) => Some(text.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Gets the direct processing-instruction children of the [`Node`].
+    pub fn processing_instructions(&self) -> impl Iterator<Item = &str> {
+        self.children.iter().filter_map(|child| match child {
+            Child::ProcessingInstruction(text) => Some(text.as_str()),
+            _ => None,
+        })
     }
 
     /// Searches for a attribute with the specified key and return it if it is found return it as a [`prim@str`].
@@ -62,17 +275,42 @@ impl Node {
     pub fn attribute(&self, key: &str) -> Result<&str> {
         #[cfg(feature = "log")]
         trace!("searching for attribute '{}' in <{}>", key, self.name());
+        self.attributes.get(key).ok_or_else(|| {
+            ParseNodeError::MissingAttribute(key.to_owned(), self.name.clone()).into()
+        })
+    }
+
+    /// Searches for an attribute whose resolved namespace and local name match the given values, mirroring
+    /// [`childs_by_qname`](Self::childs_by_qname) for elements.
+    ///
+    /// `namespace` being `None` matches attributes that carry no namespace at all, which is also what unprefixed
+    /// attributes resolve to (the default `xmlns=` declaration does not apply to attributes).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the [`Node`] has no attribute matching the given namespace and local
+    /// name.
+    pub fn attribute_by_qname(&self, namespace: Option<&str>, local_name: &str) -> Result<&str> {
+        #[cfg(feature = "log")]
+        trace!(
+            "searching for attribute {{{:?}}}{} in <{}>",
+            namespace,
+            local_name,
+            self.name()
+        );
         self.attributes
-            .get(key)
-            .map(std::string::String::as_str)
+            .get_by_qname(namespace, local_name)
             .ok_or_else(|| {
-                ParseNodeError::MissingAttribute(key.to_owned(), self.name.clone()).into()
+                ParseNodeError::MissingAttribute(local_name.to_owned(), self.name.clone()).into()
             })
     }
 
     /// Gets the childs of the [`Node`] as an [`Iterator`].
     pub fn childs(&self) -> impl Iterator<Item = &Self> {
-        self.childs.iter()
+        self.children.iter().filter_map(|child| match child {
+            Child::Element(node) => Some(node),
+            _ => None,
+        })
     }
 
     /// Checks if the [`Node`] has an attribute with the given key.
@@ -86,13 +324,13 @@ impl Node {
     /// Checks if the [`Node`] has childs.
     #[must_use]
     pub fn has_childs(&self) -> bool {
-        !self.childs.is_empty()
+        self.childs().next().is_some()
     }
 
     /// Gets the amount of childs the [`Node`] has.
     #[must_use]
     pub fn child_count(&self) -> usize {
-        self.childs.len()
+        self.childs().count()
     }
 
     /// Searches for a child with the given name.
@@ -119,7 +357,146 @@ impl Node {
             name,
             self.name()
         );
-        self.childs.iter().filter(move |c| c.name == name)
+        self.childs().filter(move |c| c.name == name)
+    }
+
+    /// Returns an iterator with all childs whose resolved namespace and local name match the given values.
+    ///
+    /// `namespace` being `None` matches childs that carry no namespace at all.
+    pub fn childs_by_qname<'a, 'n: 'a>(
+        &'a self,
+        namespace: Option<&'n str>,
+        local_name: &'n str,
+    ) -> impl Iterator<Item = &'a Self> + 'a {
+        #[cfg(feature = "log")]
+        trace!(
+            "construct iterator from all childs {{{:?}}}{} from parent <{}>",
+            namespace,
+            local_name,
+            self.name()
+        );
+        self.childs()
+            .filter(move |c| c.namespace() == namespace && c.local_name() == local_name)
+    }
+
+    /// Returns a depth-first, pre-order iterator over every descendant of the current [`Node`] (not including
+    /// itself).
+    #[must_use]
+    pub fn descendants(&self) -> Descendants<'_> {
+        let mut stack: Vec<&Self> = self.childs().collect();
+        stack.reverse();
+        Descendants { stack }
+    }
+
+    /// Returns an iterator over every [`Node`] reachable from the current node by walking the given
+    /// slash-separated path of child names, e.g. `"results/match/score"`.
+    pub fn find_all<'a, 'p: 'a>(&'a self, path: &'p str) -> impl Iterator<Item = &'a Self> + 'a {
+        #[cfg(feature = "log")]
+        trace!("searching for path \"{}\" inside of <{}>", path, self.name());
+        path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .fold(vec![self], |current, segment| {
+                current
+                    .into_iter()
+                    .flat_map(|node| node.childs_by_name(segment))
+                    .collect()
+            })
+            .into_iter()
+    }
+
+    /// Returns the first [`Node`] reachable from the current node via the given slash-separated path of child
+    /// names (see [`find_all`](Self::find_all)).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if no node is reachable via the given path.
+    pub fn find<'a, 'p: 'a>(&'a self, path: &'p str) -> Result<&'a Self> {
+        self.find_all(path)
+            .next()
+            .ok_or_else(|| ParseNodeError::MissingChild(path.to_owned(), self.name.clone()).into())
+    }
+
+    /// Converts this [`Node`] into a lossless [`Record`] value, suitable for handing off to code that works with
+    /// ordinary nested records instead of [`Node`] trees.
+    #[must_use]
+    pub fn to_record(&self) -> Record {
+        Record::Element {
+            tag: self.name.clone(),
+            namespace: self.namespace.clone(),
+            attributes: self
+                .attributes
+                .iter_namespaced()
+                .map(|(k, ns, v)| (k.to_owned(), ns.map(str::to_owned), v.to_owned()))
+                .collect(),
+            content: self.children.iter().map(Child::to_record).collect(),
+        }
+    }
+
+    /// Builds a [`Node`] back from a [`Record`] produced by [`Node::to_record`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `record` is not a [`Record::Element`].
+    pub fn try_from_record(record: &Record) -> Result<Self> {
+        let Record::Element {
+            tag,
+            namespace,
+            attributes,
+            content,
+        } = record
+        else {
+            return Err(ParseNodeError::NotAnElement(record.kind().to_owned()).into());
+        };
+
+        Ok(Self {
+            name: tag.clone(),
+            namespace: namespace.clone(),
+            attributes: attributes.iter().fold(
+                AttributeMap::new(),
+                |mut map, (key, namespace, value)| {
+                    map.insert_namespaced(key.clone(), namespace.clone(), value.clone());
+                    map
+                },
+            ),
+            children: content
+                .iter()
+                .map(Child::try_from_record)
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
+
+    /// Builds the [`BytesStart`] for this [`Node`], re-declaring `xmlns`/`xmlns:prefix` bindings for its own
+    /// namespace and for any namespace resolved onto its attributes, for every prefix that differs from what the
+    /// parent scope already has bound. The declarations are written before the node's own attributes.
+    fn start_with_namespace<'a>(&'a self, parent_bindings: &NamespaceBindings) -> BytesStart<'a> {
+        let mut start = BytesStart::new(&self.name);
+
+        let mut required: Vec<(Option<String>, &str)> = Vec::new();
+        if let Some(namespace) = &self.namespace {
+            required.push((self.name_prefix().map(str::to_owned), namespace.as_str()));
+        }
+        for (prefix, namespace) in self.attributes.namespaced_prefixes() {
+            let key = Some(prefix.to_owned());
+            if !required.iter().any(|(k, _)| *k == key) {
+                required.push((key, namespace));
+            }
+        }
+
+        for (prefix, namespace) in required {
+            if parent_bindings.get(&prefix).map(String::as_str) != Some(namespace) {
+                let key = prefix
+                    .as_deref()
+                    .map_or_else(|| "xmlns".to_owned(), |p| format!("xmlns:{p}"));
+                start.push_attribute((key.as_str(), namespace));
+            }
+        }
+
+        start.extend_attributes(self.attributes.iter().map(|(k, v)| Attribute {
+            key: QName(k.as_bytes()),
+            value: Cow::Borrowed(v.as_bytes()),
+        }));
+
+        start
     }
 
     /// Write the XML [`Node`] as a character stream to the given [`Writer`]. Internal function only.
@@ -127,24 +504,40 @@ impl Node {
     /// # Errors
     ///
     /// This function will return an error if the event writing on the [`Writer`] fails.
-    fn write_to_impl<W>(&self, writer: &mut Writer<W>) -> Result<()>
+    fn write_to_impl<W>(&self, writer: &mut Writer<W>, parent_bindings: &NamespaceBindings) -> Result<()>
     where
         W: std::io::Write,
     {
-        let start = BytesStart::from(self);
+        let start = self.start_with_namespace(parent_bindings);
 
-        if self.childs.is_empty() && self.content.is_empty() {
+        let mut bindings = parent_bindings.clone();
+        if let Some(namespace) = &self.namespace {
+            bindings.insert(self.name_prefix().map(str::to_owned), namespace.clone());
+        }
+        for (prefix, namespace) in self.attributes.namespaced_prefixes() {
+            bindings.insert(Some(prefix.to_owned()), namespace.to_owned());
+        }
+
+        if self.children.is_empty() {
             writer.write_event(Event::Empty(start))?;
         } else {
             writer.write_event(Event::Start(start))?;
 
-            if !self.content.is_empty() {
-                writer.write_event(Event::Text(BytesText::new(&self.content)))?;
-            }
-
-            if !self.childs.is_empty() {
-                for child in &self.childs {
-                    child.write_to(writer)?;
+            for child in &self.children {
+                match child {
+                    Child::Text(text) => {
+                        writer.write_event(Event::Text(BytesText::new(text)))?;
+                    }
+                    Child::Element(node) => node.write_to_impl(writer, &bindings)?,
+                    Child::Comment(text) => {
+                        writer.write_event(Event::Comment(BytesText::from_escaped(text.as_str())))?;
+                    }
+                    Child::CData(text) => {
+                        writer.write_event(Event::CData(BytesCData::new(text)))?;
+                    }
+                    Child::ProcessingInstruction(text) => {
+                        writer.write_event(Event::PI(BytesPI::new(text.as_str())))?;
+                    }
                 }
             }
 
@@ -165,12 +558,44 @@ impl Node {
     {
         #[cfg(feature = "log")]
         trace!("writing <{}>", self.name());
-        self.write_to_impl(writer)?;
+        self.write_to_impl(writer, &NamespaceBindings::new())?;
         writer.get_mut().flush()?;
 
         Ok(())
     }
 
+    /// Writes the XML [`Node`] as an indented, human-readable character stream to the given writer, using
+    /// `indent_char` repeated `indent_size` times for each nesting level. No line break or indentation is written
+    /// directly after a run of text, so an element whose only content is text stays on a single line. Elsewhere in
+    /// mixed content, for example between two child elements, line breaks and indentation are still inserted.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the event writing on the underlying writer fails.
+    pub fn write_pretty_to<W>(&self, writer: W, indent_char: u8, indent_size: usize) -> Result<W>
+    where
+        W: Write,
+    {
+        #[cfg(feature = "log")]
+        trace!("writing <{}> with indentation", self.name());
+        let mut writer = Writer::new_with_indent(writer, indent_char, indent_size);
+        self.write_to_impl(&mut writer, &NamespaceBindings::new())?;
+        writer.get_mut().flush()?;
+
+        Ok(writer.into_inner())
+    }
+
+    /// Serializes the [`Node`] to an indented, human-readable [`String`], using `indent_char` repeated
+    /// `indent_size` times for each nesting level.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the event writing fails or if the produced bytes are not valid UTF-8.
+    pub fn to_pretty_string(&self, indent_char: u8, indent_size: usize) -> Result<String> {
+        let buf = self.write_pretty_to(Cursor::new(Vec::new()), indent_char, indent_size)?;
+        Ok(str::from_utf8(&buf.into_inner())?.to_owned())
+    }
+
     /// Parses the stream from a [`Reader`] to a [`Node`]
     ///
     /// # Errors
@@ -181,25 +606,49 @@ impl Node {
         R: BufRead,
     {
         let mut node_stack = VecDeque::<Self>::new();
+        let mut ns_stack: Vec<NamespaceBindings> = vec![NamespaceBindings::new()];
         let mut buf = Vec::new();
 
-        let node = loop {
-            match reader.read_event_into(&mut buf) {
+        Self::read_events(reader, &mut buf, &mut node_stack, &mut ns_stack)
+    }
+
+    /// Core event-dispatch loop shared by [`Node::read_from`] and [`NodeReader`]: keeps consuming events from
+    /// `reader` into `node_stack`/`ns_stack` until the outermost element on `node_stack` closes, then returns it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the [`Reader`] gets an errous value or if the end of the stream is
+    /// reached before the outermost element closes.
+    fn read_events<R>(
+        reader: &mut Reader<R>,
+        buf: &mut Vec<u8>,
+        node_stack: &mut VecDeque<Self>,
+        ns_stack: &mut Vec<NamespaceBindings>,
+    ) -> Result<Self>
+    where
+        R: BufRead,
+    {
+        loop {
+            buf.clear();
+            match reader.read_event_into(buf) {
                 Ok(Event::Start(ref start)) => {
                     #[cfg(feature = "log")]
                     trace!("Read start event");
-                    let node = Self::try_from(start)?;
+                    let bindings = extend_namespace_bindings(start, ns_stack.last())?;
+                    let node = Self::from_start(start, &bindings)?;
+                    ns_stack.push(bindings);
                     node_stack.push_back(node);
                 }
                 Ok(Event::Empty(ref start)) => {
                     #[cfg(feature = "log")]
                     trace!("Read empty event");
-                    let node = Self::try_from(start)?;
+                    let bindings = extend_namespace_bindings(start, ns_stack.last())?;
+                    let node = Self::from_start(start, &bindings)?;
                     if let Some(mut parent) = node_stack.pop_back() {
-                        parent.childs.push(node);
+                        parent.children.push(Child::Element(node));
                         node_stack.push_back(parent);
                     } else {
-                        break Ok(node);
+                        return Ok(node);
                     }
                 }
                 Ok(Event::End(ref end)) => {
@@ -207,12 +656,13 @@ impl Node {
                     trace!("Read end event");
                     #[cfg(not(feature = "log"))]
                     let _ = end;
+                    ns_stack.pop();
                     if let Some(node) = node_stack.pop_back() {
                         if let Some(mut parent) = node_stack.pop_back() {
-                            parent.childs.push(node);
+                            parent.children.push(Child::Element(node));
                             node_stack.push_back(parent);
                         } else {
-                            break Ok(node);
+                            return Ok(node);
                         }
                     } else {
                         #[cfg(feature = "log")]
@@ -225,26 +675,217 @@ impl Node {
                 Ok(Event::Text(ref t)) => {
                     #[cfg(feature = "log")]
                     trace!("Read text event");
-                    let content = str::from_utf8(t)?.trim();
+                    let content = str::from_utf8(t)?;
                     if !content.is_empty() {
                         if let Some(node) = node_stack.back_mut() {
-                            node.content += content;
+                            if let Some(Child::Text(text)) = node.children.last_mut() {
+                                text.push_str(content);
+                            } else {
+                                node.children.push(Child::Text(content.to_owned()));
+                            }
                         } else {
                             #[cfg(feature = "log")]
                             warn!("Found characters {} outside of any node", content);
                         }
                     }
                 }
-                Ok(Event::Eof) => break Err(Error::Eof),
-                Err(e) => break Err(Error::from(e)),
+                Ok(Event::Comment(ref e)) => {
+                    #[cfg(feature = "log")]
+                    trace!("Read comment event");
+                    let text = str::from_utf8(e)?.to_owned();
+                    if let Some(node) = node_stack.back_mut() {
+                        node.children.push(Child::Comment(text));
+                    } else {
+                        #[cfg(feature = "log")]
+                        warn!("Found comment <!--{}--> outside of any node", text);
+                    }
+                }
+                Ok(Event::CData(ref e)) => {
+                    #[cfg(feature = "log")]
+                    trace!("Read CDATA event");
+                    let text = str::from_utf8(e)?.to_owned();
+                    if let Some(node) = node_stack.back_mut() {
+                        node.children.push(Child::CData(text));
+                    } else {
+                        #[cfg(feature = "log")]
+                        warn!("Found CDATA section <![CDATA[{}]]> outside of any node", text);
+                    }
+                }
+                Ok(Event::PI(ref e)) => {
+                    #[cfg(feature = "log")]
+                    trace!("Read processing instruction event");
+                    let text = str::from_utf8(e)?.to_owned();
+                    if let Some(node) = node_stack.back_mut() {
+                        node.children.push(Child::ProcessingInstruction(text));
+                    } else {
+                        #[cfg(feature = "log")]
+                        warn!("Found processing instruction <?{}?> outside of any node", text);
+                    }
+                }
+                Ok(Event::Eof) => return Err(Error::Eof),
+                Err(e) => return Err(Error::from(e)),
                 #[cfg(feature = "log")]
                 ev => info!("Read other event: {:?}", ev),
                 #[cfg(not(feature = "log"))]
                 _ => {}
             }
-        }?;
+        }
+    }
+}
+
+/// A pull-based, streaming reader that yields one subtree at a time from a large XML document instead of
+/// materializing the whole tree up front.
+///
+/// Only elements found at the configured `depth` (`1` for the direct childs of the document root, e.g. every
+/// `<entry>` in an Atom-style `<feed>`) are fully parsed into a [`Node`]; everything else is skipped over while
+/// just tracking nesting and namespace bindings, so only the currently-yielded subtree is ever held in memory.
+pub struct NodeReader<R> {
+    reader: Reader<R>,
+    buf: Vec<u8>,
+    ns_stack: Vec<NamespaceBindings>,
+    depth: usize,
+    current_depth: usize,
+}
+
+impl<R> NodeReader<R>
+where
+    R: BufRead,
+{
+    /// Creates a [`NodeReader`] that yields every element found at the given nesting `depth`.
+    #[must_use]
+    pub fn new(reader: Reader<R>, depth: usize) -> Self {
+        Self {
+            reader,
+            buf: Vec::new(),
+            ns_stack: vec![NamespaceBindings::new()],
+            depth,
+            current_depth: 0,
+        }
+    }
+}
+
+impl<R> Iterator for NodeReader<R>
+where
+    R: BufRead,
+{
+    type Item = Result<Node>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.buf.clear();
+            match self.reader.read_event_into(&mut self.buf) {
+                Ok(Event::Start(ref start)) => {
+                    let bindings = match extend_namespace_bindings(start, self.ns_stack.last()) {
+                        Ok(bindings) => bindings,
+                        Err(e) => return Some(Err(e)),
+                    };
+
+                    if self.current_depth == self.depth {
+                        let mut node_stack = match Node::from_start(start, &bindings) {
+                            Ok(node) => VecDeque::from([node]),
+                            Err(e) => return Some(Err(e)),
+                        };
+                        let mut ns_stack = vec![bindings];
+                        return Some(Node::read_events(
+                            &mut self.reader,
+                            &mut self.buf,
+                            &mut node_stack,
+                            &mut ns_stack,
+                        ));
+                    }
 
-        Ok(node)
+                    self.current_depth += 1;
+                    self.ns_stack.push(bindings);
+                }
+                Ok(Event::Empty(ref start)) => {
+                    if self.current_depth == self.depth {
+                        let node = extend_namespace_bindings(start, self.ns_stack.last())
+                            .and_then(|bindings| Node::from_start(start, &bindings));
+                        return Some(node);
+                    }
+                }
+                Ok(Event::End(_)) => {
+                    self.current_depth = self.current_depth.saturating_sub(1);
+                    self.ns_stack.pop();
+                }
+                Ok(Event::Eof) => return None,
+                Err(e) => return Some(Err(Error::from(e))),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Extends a parent's namespace bindings with the `xmlns`/`xmlns:prefix` declarations found on `start`.
+fn extend_namespace_bindings(
+    start: &BytesStart,
+    parent: Option<&NamespaceBindings>,
+) -> Result<NamespaceBindings> {
+    let mut bindings = parent.cloned().unwrap_or_default();
+
+    for attribute in start.attributes() {
+        let attribute = attribute?;
+        let key = str::from_utf8(attribute.key.as_ref())?;
+        let value = str::from_utf8(&attribute.value)?.to_owned();
+
+        if key == "xmlns" {
+            bindings.insert(None, value);
+        } else if let Some(prefix) = key.strip_prefix("xmlns:") {
+            bindings.insert(Some(prefix.to_owned()), value);
+        }
+    }
+
+    Ok(bindings)
+}
+
+impl Node {
+    /// Builds a [`Node`] from a [`BytesStart`] event, resolving its namespace against the given bindings.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the name or an attribute is not valid UTF-8.
+    fn from_start(value: &BytesStart, bindings: &NamespaceBindings) -> Result<Self> {
+        let name = str::from_utf8(value.name().as_ref())?.to_owned();
+        let prefix = value
+            .name()
+            .prefix()
+            .map(|p| str::from_utf8(p.as_ref()).map(str::to_owned))
+            .transpose()?;
+        let namespace = bindings.get(&prefix).cloned();
+
+        let attributes = value
+            .attributes()
+            .filter_map(|res| {
+                res.map_err(Error::from)
+                    .and_then(|attribute| {
+                        let key = str::from_utf8(attribute.key.as_ref())?.to_owned();
+                        if key == "xmlns" || key.starts_with("xmlns:") {
+                            return Ok(None);
+                        }
+                        let value = str::from_utf8(&attribute.value)?.to_owned();
+
+                        // Unlike element names, unprefixed attributes do not inherit the default `xmlns=`
+                        // namespace, so only a prefixed attribute gets a resolved namespace here.
+                        let attr_prefix = attribute
+                            .key
+                            .prefix()
+                            .map(|p| str::from_utf8(p.as_ref()).map(str::to_owned))
+                            .transpose()?;
+                        let attr_namespace =
+                            attr_prefix.and_then(|p| bindings.get(&Some(p)).cloned());
+
+                        Ok(Some((key, attr_namespace, value)))
+                    })
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            name,
+            namespace,
+            attributes: AttributeMap(attributes),
+            children: Vec::new(),
+        })
     }
 }
 
@@ -252,20 +893,8 @@ impl<'a> TryFrom<&BytesStart<'a>> for Node {
     type Error = Error;
 
     fn try_from(value: &BytesStart<'a>) -> Result<Self> {
-        Ok(Self {
-            name: str::from_utf8(value.name().as_ref())?.to_owned(),
-            content: String::new(),
-            attributes: value
-                .attributes()
-                .map(|res| {
-                    let attribute = res?;
-                    let key = str::from_utf8(attribute.key.as_ref())?.to_owned();
-                    let value = str::from_utf8(&attribute.value)?.to_owned();
-                    Ok((key, value))
-                })
-                .collect::<Result<HashMap<_, _>>>()?,
-            childs: Vec::new(),
-        })
+        let bindings = extend_namespace_bindings(value, None)?;
+        Self::from_start(value, &bindings)
     }
 }
 
@@ -306,8 +935,9 @@ impl<'a> NodeBuilder<'a> {
     pub fn new(name: &'a str) -> Self {
         Self {
             name,
+            namespace: None,
             content: "",
-            attributes: HashMap::new(),
+            attributes: AttributeMap::new(),
             childs: Vec::new(),
         }
     }
@@ -319,6 +949,13 @@ impl<'a> NodeBuilder<'a> {
         self
     }
 
+    /// Sets the resolved namespace URI of the [`NodeBuilder`].
+    #[must_use]
+    pub const fn namespace(mut self, namespace: &'a str) -> Self {
+        self.namespace = Some(namespace);
+        self
+    }
+
     /// Sets the content of the [`NodeBuilder`].
     #[must_use]
     pub const fn content(mut self, data: &'a str) -> Self {
@@ -380,11 +1017,17 @@ impl<'a> NodeBuilder<'a> {
     /// Builds the node.
     #[must_use]
     pub fn build(self) -> Node {
+        let mut children = Vec::with_capacity(usize::from(!self.content.is_empty()) + self.childs.len());
+        if !self.content.is_empty() {
+            children.push(Child::Text(self.content.to_owned()));
+        }
+        children.extend(self.childs.into_iter().map(Child::Element));
+
         Node {
             name: self.name.to_owned(),
-            content: self.content.to_owned(),
+            namespace: self.namespace.map(str::to_owned),
             attributes: self.attributes,
-            childs: self.childs,
+            children,
         }
     }
 }
@@ -394,3 +1037,107 @@ impl<'a> From<NodeBuilder<'a>> for Node {
         builder.build()
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn namespaced_attribute_is_resolved_and_queryable_by_qname() {
+        let xml = r#"<img xmlns:xl="http://www.w3.org/1999/xlink" xl:href="pic.png"/>"#;
+        let node: Node = xml.parse().unwrap();
+
+        assert_eq!(node.attribute("xl:href").unwrap(), "pic.png");
+        assert_eq!(
+            node.attribute_by_qname(Some("http://www.w3.org/1999/xlink"), "href")
+                .unwrap(),
+            "pic.png"
+        );
+        assert!(node.attribute_by_qname(None, "href").is_err());
+    }
+
+    #[test]
+    fn namespaced_attribute_round_trips_as_well_formed_xml() {
+        let xml = r#"<img xmlns:xl="http://www.w3.org/1999/xlink" xl:href="pic.png"/>"#;
+        let node: Node = xml.parse().unwrap();
+
+        assert_eq!(node.to_string(), xml);
+    }
+
+    #[test]
+    fn record_round_trip_preserves_attribute_order() {
+        let xml = r#"<e z="1" a="2" m="3"/>"#;
+        let node: Node = xml.parse().unwrap();
+
+        let round_tripped = Node::try_from_record(&node.to_record()).unwrap();
+
+        assert_eq!(round_tripped.to_string(), xml);
+    }
+
+    #[test]
+    fn record_round_trip_preserves_attribute_namespaces() {
+        let xml = r#"<img xmlns:xl="http://www.w3.org/1999/xlink" xl:href="pic.png"/>"#;
+        let node: Node = xml.parse().unwrap();
+
+        let round_tripped = Node::try_from_record(&node.to_record()).unwrap();
+
+        assert_eq!(
+            round_tripped
+                .attribute_by_qname(Some("http://www.w3.org/1999/xlink"), "href")
+                .unwrap(),
+            "pic.png"
+        );
+    }
+
+    #[test]
+    fn mixed_content_round_trips_byte_faithfully() {
+        let xml = "<p>hello <b>world</b> again</p>";
+        let node: Node = xml.parse().unwrap();
+
+        assert_eq!(node.to_string(), xml);
+    }
+
+    #[test]
+    fn comment_cdata_and_pi_round_trip() {
+        let xml = "<root><!--a comment--><![CDATA[<raw> & unescaped]]><?target data?></root>";
+        let node: Node = xml.parse().unwrap();
+
+        assert_eq!(node.comments().collect::<Vec<_>>(), vec!["a comment"]);
+        assert_eq!(node.cdata().collect::<Vec<_>>(), vec!["<raw> & unescaped"]);
+        assert_eq!(
+            node.processing_instructions().collect::<Vec<_>>(),
+            vec!["target data"]
+        );
+        assert_eq!(node.to_string(), xml);
+    }
+
+    #[test]
+    fn node_reader_yields_direct_childs_at_depth_one() {
+        let xml = "<feed><entry>a</entry><entry>b</entry></feed>";
+        let reader = NodeReader::new(Reader::from_str(xml), 1);
+
+        let entries = reader.collect::<Result<Vec<_>>>().unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|node| node.name() == "entry"));
+        assert_eq!(entries[0].content(), "a");
+        assert_eq!(entries[1].content(), "b");
+    }
+
+    #[test]
+    fn node_reader_clears_its_scratch_buffer_between_events() {
+        let mut xml = String::from("<feed>");
+        for i in 0..50 {
+            xml.push_str(&format!("<entry>{i}</entry>"));
+        }
+        xml.push_str("</feed>");
+
+        let mut reader = NodeReader::new(Reader::from_str(&xml), 1);
+        for _ in 0..50 {
+            reader.next().unwrap().unwrap();
+        }
+
+        assert!(reader.buf.len() < xml.len() / 2);
+    }
+}
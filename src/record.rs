@@ -0,0 +1,37 @@
+/// A lossless, self-describing value representation of a [`Node`](crate::node::Node), letting callers bridge
+/// `tree-xml` trees into dynamically-typed pipelines without losing any information.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Record {
+    /// A run of character data.
+    Text(String),
+    /// A `<!-- ... -->` comment.
+    Comment(String),
+    /// A `<![CDATA[ ... ]]>` section.
+    CData(String),
+    /// A `<? ... ?>` processing instruction.
+    ProcessingInstruction(String),
+    /// An element with a tag, an optional resolved namespace, attributes, and ordered content.
+    Element {
+        /// The element's name.
+        tag: String,
+        /// The element's resolved namespace URI, if any.
+        namespace: Option<String>,
+        /// The element's attributes, in document order, each carrying its own resolved namespace URI if any.
+        attributes: Vec<(String, Option<String>, String)>,
+        /// The element's ordered content.
+        content: Vec<Record>,
+    },
+}
+
+impl Record {
+    /// Gets a short name for the kind of value this [`Record`] holds, used in error messages.
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Self::Text(_) => "Record::Text",
+            Self::Comment(_) => "Record::Comment",
+            Self::CData(_) => "Record::CData",
+            Self::ProcessingInstruction(_) => "Record::ProcessingInstruction",
+            Self::Element { .. } => "Record::Element",
+        }
+    }
+}